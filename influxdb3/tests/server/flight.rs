@@ -1,6 +1,7 @@
 use arrow_flight::sql::SqlInfo;
 use arrow_flight::Ticket;
 use arrow_util::assert_batches_sorted_eq;
+use futures::{StreamExt, TryStreamExt};
 use influxdb3_client::Precision;
 use test_helpers::assert_contains;
 
@@ -123,6 +124,83 @@ async fn flight() -> Result<(), influxdb3_client::Error> {
         );
     }
 
+    // Get Tables with a table name filter pattern
+    {
+        type OptStr = std::option::Option<&'static str>;
+        let stream = client
+            .get_tables(OptStr::None, OptStr::None, Some("c%"), vec![], false)
+            .await
+            .unwrap();
+        let batches = collect_stream(stream).await;
+
+        assert_batches_sorted_eq!(
+            [
+                "+--------------+--------------------+------------+------------+",
+                "| catalog_name | db_schema_name     | table_name | table_type |",
+                "+--------------+--------------------+------------+------------+",
+                "| public       | information_schema | columns    | VIEW       |",
+                "| public       | iox                | cpu        | BASE TABLE |",
+                "+--------------+--------------------+------------+------------+",
+            ],
+            &batches
+        );
+    }
+
+    // Get DB Schemas
+    {
+        type OptStr = std::option::Option<&'static str>;
+        let stream = client
+            .get_db_schemas(OptStr::None, OptStr::None)
+            .await
+            .unwrap();
+        let batches = collect_stream(stream).await;
+
+        assert_batches_sorted_eq!(
+            [
+                "+--------------+--------------------+",
+                "| catalog_name | db_schema_name     |",
+                "+--------------+--------------------+",
+                "| public       | information_schema |",
+                "| public       | iox                |",
+                "+--------------+--------------------+",
+            ],
+            &batches
+        );
+    }
+
+    // Get DB Schemas with a schema filter pattern
+    {
+        type OptStr = std::option::Option<&'static str>;
+        let stream = client
+            .get_db_schemas(OptStr::None, Some("io%"))
+            .await
+            .unwrap();
+        let batches = collect_stream(stream).await;
+
+        assert_batches_sorted_eq!(
+            [
+                "+--------------+----------------+",
+                "| catalog_name | db_schema_name |",
+                "+--------------+----------------+",
+                "| public       | iox            |",
+                "+--------------+----------------+",
+            ],
+            &batches
+        );
+    }
+
+    // Get Tables for a database that does not exist:
+    {
+        type OptStr = std::option::Option<&'static str>;
+        let mut client = server.flight_sql_client("does_not_exist").await;
+        let error = client
+            .get_tables(OptStr::None, OptStr::None, OptStr::None, vec![], false)
+            .await
+            .unwrap_err();
+
+        assert_contains!(error.to_string(), "database not found: does_not_exist");
+    }
+
     // Get Catalogs
     {
         let stream = client.get_catalogs().await.unwrap();
@@ -226,3 +304,131 @@ async fn flight_influxql() {
         );
     }
 }
+
+#[tokio::test]
+async fn flight_empty_result() {
+    let server = TestServer::spawn().await;
+
+    server
+        .write_lp_to_db(
+            "foo",
+            "cpu,host=s1,region=us-east usage=0.9 1\n\
+            cpu,host=s1,region=us-east usage=0.89 2\n\
+            cpu,host=s1,region=us-east usage=0.85 3",
+            Precision::Nanosecond,
+        )
+        .await
+        .unwrap();
+
+    // SQL query that matches no rows; the result may be either no batches or
+    // batches with zero rows, so only the total row count is checked:
+    {
+        let mut client = server.flight_sql_client("foo").await;
+        let response = client
+            .query("SELECT host, usage FROM cpu WHERE usage > 1.0")
+            .await
+            .unwrap();
+
+        let batches = collect_stream(response).await;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 0, "expected no rows, got {num_rows}");
+    }
+
+    // InfluxQL query that matches no rows:
+    {
+        let mut client = server.flight_client().await;
+        let ticket = Ticket::new(
+            r#"{
+                    "database": "foo",
+                    "sql_query": "SELECT host, usage FROM cpu WHERE usage > 1.0",
+                    "query_type": "influxql"
+                }"#,
+        );
+        let response = client.do_get(ticket).await.unwrap();
+
+        let batches = collect_stream(response).await;
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(num_rows, 0, "expected no rows, got {num_rows}");
+    }
+}
+
+#[tokio::test]
+async fn flight_row_count() {
+    const NUM_ROWS: usize = 10_000;
+
+    let server = TestServer::spawn().await;
+
+    let lp = (0..NUM_ROWS).fold(String::new(), |mut acc, i| {
+        acc.push_str(&format!("cpu,host=s{h} usage={i} {i}\n", h = i % 10));
+        acc
+    });
+    server
+        .write_lp_to_db("foo", lp, Precision::Nanosecond)
+        .await
+        .unwrap();
+
+    let mut client = server.flight_sql_client("foo").await;
+    let response = client.query("SELECT host, usage FROM cpu").await.unwrap();
+
+    // Count rows as batches arrive, rather than buffering the whole result:
+    let num_rows = response
+        .try_fold(0, |acc, batch| async move { Ok(acc + batch.num_rows()) })
+        .await
+        .expect("stream record batches");
+    assert_eq!(num_rows, NUM_ROWS);
+}
+
+#[tokio::test]
+async fn flight_concurrent_queries() {
+    const NUM_QUERIES: usize = 20;
+    const CONCURRENCY: usize = 5;
+
+    let server = TestServer::spawn().await;
+
+    server
+        .write_lp_to_db(
+            "foo",
+            "cpu,host=s1,region=us-east usage=0.9 1\n\
+            cpu,host=s1,region=us-east usage=0.89 2\n\
+            cpu,host=s1,region=us-east usage=0.85 3",
+            Precision::Nanosecond,
+        )
+        .await
+        .unwrap();
+
+    let results = futures::stream::iter(0..NUM_QUERIES)
+        .map(|i| {
+            let server = &server;
+            async move {
+                let mut client = server.flight_sql_client("foo").await;
+                let result = match client
+                    .query("SELECT host, region, time, usage FROM cpu")
+                    .await
+                {
+                    Ok(response) => response.try_collect::<Vec<_>>().await,
+                    Err(e) => Err(e),
+                };
+                (i, result)
+            }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(results.len(), NUM_QUERIES);
+    for (i, result) in results {
+        let batches = result.unwrap_or_else(|e| panic!("query {i} failed: {e}"));
+        assert_batches_sorted_eq!(
+            [
+                "+------+---------+--------------------------------+-------+",
+                "| host | region  | time                           | usage |",
+                "+------+---------+--------------------------------+-------+",
+                "| s1   | us-east | 1970-01-01T00:00:00.000000001Z | 0.9   |",
+                "| s1   | us-east | 1970-01-01T00:00:00.000000002Z | 0.89  |",
+                "| s1   | us-east | 1970-01-01T00:00:00.000000003Z | 0.85  |",
+                "+------+---------+--------------------------------+-------+",
+            ],
+            &batches
+        );
+    }
+}
@@ -80,3 +80,33 @@ async fn limits() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn max_http_request_size_from_env() {
+    let server = TestServer::configure()
+        .env("INFLUXDB3_MAX_HTTP_REQUEST_SIZE", "64")
+        .spawn()
+        .await;
+
+    // A write that fits within the limit succeeds:
+    server
+        .write_lp_to_db("foo", "cpu,host=a usage=0.9 1", Precision::Nanosecond)
+        .await
+        .unwrap();
+
+    // A write that exceeds the limit is rejected:
+    let lp = (0..10).fold(String::new(), |mut acc, i| {
+        acc.push_str(&format!("cpu,host=a usage=0.9 {i}\n"));
+        acc
+    });
+    let Err(Error::ApiError { message, .. }) = server
+        .write_lp_to_db("foo", lp, Precision::Nanosecond)
+        .await
+    else {
+        panic!("Did not error when exceeding the max request size");
+    };
+    assert!(
+        message.contains("max request size (64 bytes) exceeded"),
+        "unexpected error message: {message}"
+    );
+}
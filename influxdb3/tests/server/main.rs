@@ -23,6 +23,7 @@ mod write;
 #[derive(Debug, Default)]
 pub struct TestConfig {
     auth_token: Option<(String, String)>,
+    env: Vec<(String, String)>,
 }
 
 impl TestConfig {
@@ -36,6 +37,15 @@ impl TestConfig {
         self
     }
 
+    /// Set an environment variable on the `influxdb3 serve` process
+    ///
+    /// This can be used to set any server configuration that is exposed
+    /// through environment variables, e.g., `INFLUXDB3_MAX_HTTP_REQUEST_SIZE`.
+    pub fn env<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
     /// Spawn a new [`TestServer`] with this configuration
     ///
     /// This will run the `influxdb3 serve` command, and bind its HTTP
@@ -88,7 +98,8 @@ impl TestServer {
             .arg("serve")
             .args(["--http-bind", &bind_addr.to_string()])
             .args(["--object-store", "memory"])
-            .args(config.as_args());
+            .args(config.as_args())
+            .envs(config.env.iter().map(|(k, v)| (k, v)));
 
         // If TEST_LOG env var is not defined, discard stdout/stderr
         if std::env::var("TEST_LOG").is_err() {
@@ -1,4 +1,4 @@
-use hyper::Method;
+use hyper::{Method, StatusCode};
 use serde_json::Value;
 
 use crate::TestServer;
@@ -36,7 +36,28 @@ async fn test_ping() {
         println!("Method: {}, URL: {}", t.method, t.url);
         println!("{json:#}");
         let map = json.as_object().unwrap();
-        assert!(map.contains_key("version"));
+        // The server binary is built from the same workspace as this test, so
+        // it should report the same version:
+        assert_eq!(
+            map.get("version").and_then(Value::as_str),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
         assert!(map.contains_key("revision"));
     }
 }
+
+#[tokio::test]
+async fn test_health() {
+    let server = TestServer::spawn().await;
+    let client = reqwest::Client::new();
+
+    for path in ["/health", "/api/v1/health"] {
+        let url = format!("{base}{path}", base = server.client_addr());
+        let resp = client.get(&url).send().await.unwrap();
+        let status = resp.status();
+        let body = resp.text().await.unwrap();
+        println!("URL: {url}, status: {status}, body: {body}");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "OK");
+    }
+}
@@ -51,6 +51,61 @@ async fn api_v3_query_sql() {
     );
 }
 
+#[tokio::test]
+async fn api_v3_query_sql_csv() {
+    let server = TestServer::spawn().await;
+
+    server
+        .write_lp_to_db(
+            "foo",
+            r#"cpu,host=a msg="hello, \"world\"",val=1i 1
+            cpu,host=b val=2i 2
+            cpu,host=c msg="plain",val=3i 3"#,
+            Precision::Nanosecond,
+        )
+        .await
+        .unwrap();
+
+    let client = reqwest::Client::new();
+    let url = format!("{base}/api/v3/query_sql", base = server.client_addr());
+    let query = "SELECT host, msg, time, val FROM cpu ORDER BY time";
+    let expected = "host,msg,time,val\n\
+        a,\"hello, \"\"world\"\"\",1970-01-01T00:00:00.000000001,1\n\
+        b,,1970-01-01T00:00:00.000000002,2\n\
+        c,plain,1970-01-01T00:00:00.000000003,3\n";
+
+    // Request CSV using the format parameter
+    {
+        let resp = client
+            .get(&url)
+            .query(&[("db", "foo"), ("q", query), ("format", "csv")])
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert_eq!(expected, resp.replace("\r\n", "\n"));
+    }
+
+    // Request CSV using the Accept header
+    {
+        let resp = client
+            .get(&url)
+            .query(&[("db", "foo"), ("q", query)])
+            .header("Accept", "text/csv")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert_eq!(expected, resp.replace("\r\n", "\n"));
+    }
+}
+
 #[tokio::test]
 async fn api_v3_query_sql_params() {
     let server = TestServer::spawn().await;
@@ -293,6 +348,16 @@ async fn api_v3_query_influxql() {
                     | mem              | usage      | float     |\n\
                     +------------------+------------+-----------+",
         },
+        TestCase {
+            database: Some("foo"),
+            query: "SHOW FIELD KEYS FROM cpu",
+            expected: "+------------------+------------+-----------+\n\
+                    | iox::measurement | fieldKey   | fieldType |\n\
+                    +------------------+------------+-----------+\n\
+                    | cpu              | _series_id | string    |\n\
+                    | cpu              | usage      | float     |\n\
+                    +------------------+------------+-----------+",
+        },
         TestCase {
             database: Some("foo"),
             query: "SHOW TAG KEYS",
@@ -317,6 +382,16 @@ async fn api_v3_query_influxql() {
                     | mem              | region |\n\
                     +------------------+--------+",
         },
+        TestCase {
+            database: Some("foo"),
+            query: "SHOW TAG KEYS FROM mem",
+            expected: "+------------------+--------+\n\
+                    | iox::measurement | tagKey |\n\
+                    +------------------+--------+\n\
+                    | mem              | host   |\n\
+                    | mem              | region |\n\
+                    +------------------+--------+",
+        },
         TestCase {
             database: Some("foo"),
             query: "SHOW TAG VALUES WITH KEY = \"host\" WHERE time < 1970-01-02",
@@ -392,6 +467,23 @@ async fn api_v3_query_influxql() {
         println!("{resp}");
         assert_eq!(t.expected, resp, "query failed: {q}", q = t.query);
     }
+
+    // Metadata queries against a measurement that does not exist succeed with
+    // an empty result, rather than an error:
+    for query in [
+        "SHOW TAG KEYS FROM nonexistent",
+        "SHOW FIELD KEYS FROM nonexistent",
+    ] {
+        let resp = server
+            .api_v3_query_influxql(&[("db", "foo"), ("q", query), ("format", "json")])
+            .await;
+        let status = resp.status();
+        let body = resp.text().await.unwrap();
+        println!("\n{query}");
+        println!("{body}");
+        assert!(status.is_success(), "query failed: {query}: {body}");
+        assert_eq!("[]", body, "query failed: {query}");
+    }
 }
 
 #[tokio::test]
@@ -690,6 +782,29 @@ async fn api_v1_query() {
     }
 }
 
+#[tokio::test]
+async fn api_v1_query_invalid_influxql() {
+    let server = TestServer::spawn().await;
+
+    server
+        .write_lp_to_db("foo", "cpu,host=a usage=0.9 1", Precision::Second)
+        .await
+        .unwrap();
+
+    let resp = server
+        .api_v1_query(&[("db", "foo"), ("q", "SELEC time, host, usage FROM cpu")])
+        .await;
+    let status = resp.status();
+    let body = resp.json::<Value>().await.unwrap();
+    println!("{body:#}");
+
+    assert_eq!(hyper::StatusCode::BAD_REQUEST, status);
+    assert_contains!(
+        body["error"].as_str().expect("error field is a string"),
+        "error in InfluxQL statement: parsing error"
+    );
+}
+
 #[tokio::test]
 async fn api_v1_query_chunked() {
     let server = TestServer::spawn().await;
@@ -272,3 +272,81 @@ async fn api_v2_write_round_trip() {
         +------------------+-------------------------------+------+-------+"
     );
 }
+
+#[tokio::test]
+async fn concurrent_writes_create_single_database() {
+    const NUM_CLIENTS: usize = 10;
+
+    let server = TestServer::spawn().await;
+    let write_url = format!("{base}/api/v3/write_lp", base = server.client_addr());
+
+    // Each writer uses its own client, and therefore its own connection, so that
+    // the requests to create the database race on the server:
+    let responses = futures::future::join_all((0..NUM_CLIENTS).map(|i| {
+        let write_url = write_url.clone();
+        async move {
+            let resp = reqwest::Client::new()
+                .post(write_url)
+                .query(&[("db", "foo")])
+                .body(format!("cpu,host=c{i} usage=0.5 1"))
+                .send()
+                .await
+                .expect("send /api/v3/write_lp request");
+            let status = resp.status();
+            let body = resp.text().await.expect("read response body");
+            (status, body)
+        }
+    }))
+    .await;
+
+    // Every writer should get a successful response with no error payload, whether
+    // its request created the database or found it already created:
+    for (i, (status, body)) in responses.into_iter().enumerate() {
+        assert_eq!(
+            status,
+            StatusCode::OK,
+            "write from client {i} failed with body: {body}"
+        );
+        assert!(body.is_empty(), "unexpected body for client {i}: {body}");
+    }
+
+    let resp = server
+        .api_v3_query_influxql(&[("q", "SHOW DATABASES"), ("format", "pretty")])
+        .await
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(
+        resp,
+        "+---------------+\n\
+        | iox::database |\n\
+        +---------------+\n\
+        | foo           |\n\
+        +---------------+"
+    );
+
+    let resp = reqwest::Client::new()
+        .get(format!(
+            "{base}/api/v3/query_sql",
+            base = server.client_addr()
+        ))
+        .query(&[
+            ("db", "foo"),
+            ("q", "SELECT count(*) AS n FROM cpu"),
+            ("format", "pretty"),
+        ])
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(
+        resp,
+        "+----+\n\
+        | n  |\n\
+        +----+\n\
+        | 10 |\n\
+        +----+"
+    );
+}
@@ -261,6 +261,18 @@ impl Error {
                     .body(body)
                     .unwrap()
             }
+            Self::InfluxqlRewrite(_) => {
+                let err: ErrorMessage<()> = ErrorMessage {
+                    error: self.to_string(),
+                    data: None,
+                };
+                let serialized = serde_json::to_string(&err).unwrap();
+                let body = Body::from(serialized);
+                Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .body(body)
+                    .unwrap()
+            }
             Self::PartialLpWrite(data) => {
                 let err = ErrorMessage {
                     error: "partial write of line protocol occurred".into(),
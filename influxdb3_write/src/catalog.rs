@@ -127,13 +127,21 @@ impl Catalog {
                 db
             }
             None => {
-                info!("return new db {}", db_name);
                 let mut inner = self.inner.write();
 
+                // Another writer may have created the database between releasing
+                // the read lock and acquiring the write lock, in which case it must
+                // be returned rather than replaced with an empty schema:
+                if let Some(db) = inner.databases.get(db_name) {
+                    info!("return existing db {}", db_name);
+                    return Ok((inner.sequence, Arc::clone(db)));
+                }
+
                 if inner.databases.len() >= Self::NUM_DBS_LIMIT {
                     return Err(Error::TooManyDbs);
                 }
 
+                info!("return new db {}", db_name);
                 let db = Arc::new(DatabaseSchema::new(db_name));
                 inner.databases.insert(db.name.clone(), Arc::clone(&db));
                 db
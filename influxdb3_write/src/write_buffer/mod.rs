@@ -8,7 +8,8 @@ mod table_buffer;
 
 use crate::cache::ParquetCache;
 use crate::catalog::{
-    Catalog, DatabaseSchema, TableDefinition, SERIES_ID_COLUMN_NAME, TIME_COLUMN_NAME,
+    Catalog, DatabaseSchema, Error as CatalogError, TableDefinition, SERIES_ID_COLUMN_NAME,
+    TIME_COLUMN_NAME,
 };
 use crate::chunk::ParquetChunk;
 use crate::persister::PersisterImpl;
@@ -431,6 +432,9 @@ impl<W: Wal, T: TimeProvider> WriteBuffer for WriteBufferImpl<W, T> {}
 
 /// Returns a validated result and the sequence number of the catalog before any updates were
 /// applied.
+///
+/// If the catalog is updated by another write between validating the schema and applying the
+/// update, validation is retried against the latest schema.
 pub(crate) fn parse_validate_and_update_catalog(
     db_name: NamespaceName<'static>,
     lp: &str,
@@ -440,25 +444,36 @@ pub(crate) fn parse_validate_and_update_catalog(
     accept_partial: bool,
     precision: Precision,
 ) -> Result<ValidationResult> {
-    let (sequence, db) = catalog.db_or_create(db_name.as_str())?;
-    let mut result = parse_validate_and_update_schema(
-        lp,
-        &db,
-        db_name,
-        ingest_time,
-        segment_duration,
-        accept_partial,
-        precision,
-        sequence,
-    )?;
+    loop {
+        let (sequence, db) = catalog.db_or_create(db_name.as_str())?;
+        let mut result = parse_validate_and_update_schema(
+            lp,
+            &db,
+            db_name.clone(),
+            ingest_time,
+            segment_duration,
+            accept_partial,
+            precision,
+            sequence,
+        )?;
 
-    if let Some(schema) = result.schema.take() {
-        debug!("replacing schema for {:?}", schema);
+        if let Some(schema) = result.schema.take() {
+            debug!("replacing schema for {:?}", schema);
 
-        catalog.replace_database(sequence, Arc::new(schema))?;
-    }
+            match catalog.replace_database(sequence, Arc::new(schema)) {
+                Ok(()) => (),
+                // Another write updated the catalog first, which only happens when that
+                // write succeeded, so retrying here always makes progress:
+                Err(CatalogError::CatalogUpdatedElsewhere) => {
+                    debug!("catalog updated elsewhere, retrying schema validation");
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
 
-    Ok(result)
+        return Ok(result);
+    }
 }
 
 /// Takes &str of line protocol, parses lines, validates the schema, and inserts new columns
@@ -929,6 +944,38 @@ mod tests {
         assert_eq!(db.tables.get("foo").unwrap().columns().len(), 3);
     }
 
+    #[test]
+    fn concurrent_writes_update_catalog() {
+        const NUM_WRITERS: usize = 10;
+
+        let catalog = Catalog::new();
+        let db_name = NamespaceName::new("foo").unwrap();
+
+        // Each writer creates the database (if it does not exist yet) and adds its own
+        // table, so no writer should fail or lose another writer's table:
+        std::thread::scope(|s| {
+            for i in 0..NUM_WRITERS {
+                let catalog = &catalog;
+                let db_name = db_name.clone();
+                s.spawn(move || {
+                    parse_validate_and_update_catalog(
+                        db_name,
+                        &format!("table_{i} bar=1 10"),
+                        catalog,
+                        Time::from_timestamp_nanos(0),
+                        SegmentDuration::new_5m(),
+                        false,
+                        Precision::Nanosecond,
+                    )
+                    .unwrap_or_else(|e| panic!("write from writer {i} failed: {e}"));
+                });
+            }
+        });
+
+        let db = catalog.db_schema("foo").unwrap();
+        assert_eq!(db.tables.len(), NUM_WRITERS);
+    }
+
     #[tokio::test]
     async fn buffers_and_persists_to_wal() {
         let dir = test_helpers::tmp_dir().unwrap().into_path();